@@ -1,4 +1,4 @@
-use advent_of_code_util::{base_aoc, parse::read_list_of_lists};
+use advent_of_code_util::{parse::read_list_of_lists, parsed_aoc};
 
 fn is_safe(report: &[usize]) -> bool {
     assert!(report.len() >= 2);
@@ -27,16 +27,19 @@ fn is_safe_with_any_removed(report: &[usize]) -> bool {
     false
 }
 
-fn get_program_output(input_file: &str) -> (usize, usize) {
-    let input = read_list_of_lists(input_file, " ", |v| v.parse::<usize>().unwrap());
+fn parse_input(input_file: &str) -> Vec<Vec<usize>> {
+    read_list_of_lists(input_file, " ", |v| v.parse::<usize>().unwrap())
+}
+
+fn part_1(input: &[Vec<usize>]) -> usize {
+    input.iter().filter(|report| is_safe(report)).count()
+}
 
-    let answer_1 = input.iter().filter(|report| is_safe(report)).count();
-    let answer_2 = input
+fn part_2(input: &[Vec<usize>]) -> usize {
+    input
         .iter()
         .filter(|report| is_safe_with_any_removed(report))
-        .count();
-
-    (answer_1, answer_2)
+        .count()
 }
 
-base_aoc!(2, 4);
+parsed_aoc!(2, 4);
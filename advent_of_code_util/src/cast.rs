@@ -0,0 +1,50 @@
+use crate::Coordinate;
+
+#[track_caller]
+pub fn to_usize(value: i64) -> usize {
+    usize::try_from(value).unwrap_or_else(|_| panic!("{} can't be used as a usize", value))
+}
+
+#[track_caller]
+pub fn to_i64(value: usize) -> i64 {
+    i64::try_from(value).unwrap_or_else(|_| panic!("{} doesn't fit in an i64", value))
+}
+
+#[track_caller]
+pub fn to_isize(value: usize) -> isize {
+    isize::try_from(value).unwrap_or_else(|_| panic!("{} doesn't fit in an isize", value))
+}
+
+/**
+bounds is (width, height), returns None if x or y is negative or past the edge instead of wrapping around
+*/
+pub fn checked_index(x: i64, y: i64, bounds: (usize, usize)) -> Option<Coordinate> {
+    let (width, height) = bounds;
+    let x = usize::try_from(x).ok().filter(|&x| x < width)?;
+    let y = usize::try_from(y).ok().filter(|&y| y < height)?;
+    Some(Coordinate { x, y })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cast::*;
+
+    #[test]
+    fn checked_index_test() {
+        let position = Coordinate { x: 0, y: 3 };
+        let (dx, dy) = (-1, 1);
+        assert_eq!(
+            checked_index(to_i64(position.x) + dx, to_i64(position.y) + dy, (5, 5)),
+            None
+        );
+        assert_eq!(checked_index(4, 4, (5, 5)), Some(Coordinate { x: 4, y: 4 }));
+        assert_eq!(checked_index(4, 5, (5, 5)), None);
+        assert_eq!(to_usize(7), 7);
+    }
+
+    #[test]
+    #[should_panic(expected = "-1 can't be used as a usize")]
+    fn to_usize_negative_test() {
+        to_usize(-1);
+    }
+}
@@ -0,0 +1,126 @@
+use std::ops::{Index, IndexMut};
+
+use itertools::Itertools;
+
+use crate::{matrix::Adjacency, Coordinate};
+
+/**
+a grid whose size is known at compile time, so it lives on the stack and can be copied, hashed and ordered cheaply as a search state
+*/
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub struct FixedGrid<T, const W: usize, const H: usize> {
+    cells: [[T; W]; H],
+}
+impl<T, const W: usize, const H: usize> FixedGrid<T, W, H> {
+    pub fn new(cells: [[T; W]; H]) -> Self {
+        FixedGrid { cells }
+    }
+
+    pub fn filled(value: T) -> Self
+    where
+        T: Copy,
+    {
+        FixedGrid {
+            cells: [[value; W]; H],
+        }
+    }
+
+    /**
+    returns None unless v is exactly H rows of W items
+    */
+    pub fn from_vec(v: &[Vec<T>]) -> Option<Self>
+    where
+        T: Clone,
+    {
+        if v.len() != H || v.iter().any(|row| row.len() != W) {
+            return None;
+        }
+        Some(FixedGrid {
+            cells: std::array::from_fn(|y| std::array::from_fn(|x| v[y][x].clone())),
+        })
+    }
+
+    pub fn to_vec(&self) -> Vec<Vec<T>>
+    where
+        T: Clone,
+    {
+        self.cells.iter().map(|row| row.to_vec()).collect()
+    }
+
+    pub fn width(&self) -> usize {
+        W
+    }
+
+    pub fn height(&self) -> usize {
+        H
+    }
+
+    pub fn rows(&self) -> &[[T; W]; H] {
+        &self.cells
+    }
+
+    pub fn get(&self, position: Coordinate) -> Option<&T> {
+        self.cells
+            .get(position.y)
+            .and_then(|row| row.get(position.x))
+    }
+
+    pub fn get_mut(&mut self, position: Coordinate) -> Option<&mut T> {
+        self.cells
+            .get_mut(position.y)
+            .and_then(|row| row.get_mut(position.x))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Coordinate> {
+        (0..H)
+            .cartesian_product(0..W)
+            .map(|(y, x)| Coordinate { x, y })
+    }
+
+    pub fn neighbors(&self, position: Coordinate, adjacency: Adjacency) -> Vec<Coordinate> {
+        match adjacency {
+            Adjacency::NonDiagonal => position.get_surrounding_non_diagonal_coordinates(W, H),
+            Adjacency::WithDiagonals => position.get_surrounding_coordinates(W, H),
+        }
+    }
+}
+impl<T, const W: usize, const H: usize> Index<Coordinate> for FixedGrid<T, W, H> {
+    type Output = T;
+
+    fn index(&self, position: Coordinate) -> &Self::Output {
+        &self.cells[position.y][position.x]
+    }
+}
+impl<T, const W: usize, const H: usize> IndexMut<Coordinate> for FixedGrid<T, W, H> {
+    fn index_mut(&mut self, position: Coordinate) -> &mut Self::Output {
+        &mut self.cells[position.y][position.x]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{fixed_grid::*, matrix::label_regions};
+
+    #[test]
+    fn fixed_grid_test() {
+        let mut grid = FixedGrid::<u8, 3, 2>::from_vec(&[vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        assert_eq!(grid[Coordinate { x: 2, y: 1 }], 6);
+        assert_eq!(grid.get(Coordinate { x: 3, y: 0 }), None);
+        grid[Coordinate { x: 0, y: 0 }] = 9;
+        assert_eq!(grid.rows(), &[[9, 2, 3], [4, 5, 6]]);
+        assert_eq!(grid.positions().count(), 6);
+        assert_eq!(
+            grid.neighbors(Coordinate { x: 0, y: 1 }, Adjacency::NonDiagonal),
+            vec![Coordinate { x: 0, y: 0 }, Coordinate { x: 1, y: 1 }]
+        );
+        assert_eq!(label_regions(&grid.to_vec(), |_, _| true).1, 1);
+        assert_eq!(
+            FixedGrid::<u8, 2, 2>::from_vec(&[vec![1, 2, 3], vec![4, 5, 6]]),
+            None
+        );
+        assert_eq!(
+            FixedGrid::<u8, 2, 2>::filled(0),
+            FixedGrid::new([[0; 2]; 2])
+        );
+    }
+}
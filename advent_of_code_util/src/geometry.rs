@@ -0,0 +1,208 @@
+use itertools::Itertools;
+
+use crate::{
+    cast::to_isize,
+    icoordinate::{ICoordinate, ICoordinate3},
+    Coordinate,
+};
+
+pub trait Point<const D: usize> {
+    fn components(&self) -> [isize; D];
+
+    fn manhattan_distance(&self, other: &Self) -> usize {
+        self.components()
+            .into_iter()
+            .zip(other.components())
+            .map(|(a, b)| a.abs_diff(b))
+            .sum()
+    }
+}
+impl Point<2> for Coordinate {
+    fn components(&self) -> [isize; 2] {
+        [to_isize(self.x), to_isize(self.y)]
+    }
+}
+impl Point<2> for ICoordinate {
+    fn components(&self) -> [isize; 2] {
+        [self.x, self.y]
+    }
+}
+impl Point<3> for ICoordinate3 {
+    fn components(&self) -> [isize; 3] {
+        [self.x, self.y, self.z]
+    }
+}
+
+pub fn max_pairwise_manhattan<P: Point<D>, const D: usize>(points: &[P]) -> usize {
+    points
+        .iter()
+        .tuple_combinations()
+        .map(|(a, b)| a.manhattan_distance(b))
+        .max()
+        .unwrap_or(0)
+}
+
+/**
+done one axis at a time on sorted values, so it's fast enough for thousands of points
+*/
+pub fn sum_pairwise_manhattan<P: Point<D>, const D: usize>(points: &[P]) -> usize {
+    (0..D)
+        .map(|axis| {
+            let values = points
+                .iter()
+                .map(|point| point.components()[axis])
+                .sorted()
+                .collect_vec();
+            // each value is bigger than or equal to all i values before it
+            values
+                .iter()
+                .enumerate()
+                .scan(0isize, |sum_before, (i, &value)| {
+                    let total = value * i as isize - *sum_before;
+                    *sum_before += value;
+                    Some(total as usize)
+                })
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+pub fn centroid<P: Point<D>, const D: usize>(points: &[P]) -> Option<[f64; D]> {
+    if points.is_empty() {
+        return None;
+    }
+    let mut totals = [0.0; D];
+    for point in points {
+        for (total, component) in totals.iter_mut().zip(point.components()) {
+            *total += component as f64;
+        }
+    }
+    Some(totals.map(|total| total / points.len() as f64))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::{fixtures, geometry::*, input::Input};
+
+    fn rotate(point: ICoordinate3, axes: &[usize], signs: [isize; 3]) -> ICoordinate3 {
+        let components = point.components();
+        ICoordinate3 {
+            x: signs[0] * components[axes[0]],
+            y: signs[1] * components[axes[1]],
+            z: signs[2] * components[axes[2]],
+        }
+    }
+
+    #[test]
+    fn scanner_positions_test() {
+        let input = Input::new(fixtures::DAY_19.to_string());
+        let scanners = input
+            .blocks()
+            .iter()
+            .map(|block| {
+                block[1..]
+                    .iter()
+                    .map(|line| line.parse::<ICoordinate3>().unwrap())
+                    .collect_vec()
+            })
+            .collect_vec();
+        assert_eq!(scanners.len(), 5);
+
+        // the puzzle states where each scanner is relative to scanner 0, check those against the
+        // example by placing every scanner so that 12 of its beacons line up with ones already placed
+        let positions = [
+            "0,0,0",
+            "68,-1246,-43",
+            "1105,-1205,1229",
+            "-92,-2380,-20",
+            "-20,-1133,1061",
+        ]
+        .iter()
+        .map(|s| s.parse::<ICoordinate3>().unwrap())
+        .collect_vec();
+        let rotations = (0..3)
+            .permutations(3)
+            .cartesian_product(
+                [1, -1]
+                    .into_iter()
+                    .cartesian_product([1, -1])
+                    .cartesian_product([1, -1])
+                    .map(|((x, y), z)| [x, y, z]),
+            )
+            .filter(|(axes, signs)| {
+                let inversions = axes
+                    .iter()
+                    .tuple_combinations()
+                    .filter(|(a, b)| a > b)
+                    .count();
+                let parity = if inversions % 2 == 0 { 1 } else { -1 };
+                parity * signs.iter().product::<isize>() == 1
+            })
+            .collect_vec();
+        assert_eq!(rotations.len(), 24);
+
+        let mut beacons: HashSet<ICoordinate3> = scanners[0].iter().copied().collect();
+        let mut unplaced = (1..scanners.len()).collect_vec();
+        while !unplaced.is_empty() {
+            let (index, placed) = unplaced
+                .iter()
+                .enumerate()
+                .find_map(|(index, &scanner)| {
+                    rotations.iter().find_map(|(axes, signs)| {
+                        let placed = scanners[scanner]
+                            .iter()
+                            .map(|&beacon| positions[scanner] + rotate(beacon, axes, *signs))
+                            .collect_vec();
+                        (placed
+                            .iter()
+                            .filter(|beacon| beacons.contains(beacon))
+                            .count()
+                            >= 12)
+                            .then_some((index, placed))
+                    })
+                })
+                .expect("a stated scanner position doesn't line up with the example");
+            beacons.extend(placed);
+            unplaced.remove(index);
+        }
+        assert_eq!(beacons.len(), 79);
+
+        assert_eq!(max_pairwise_manhattan(&positions), 3621);
+        let brute_force_sum: usize = positions
+            .iter()
+            .tuple_combinations()
+            .map(|(a, b)| a.manhattan_distance(b))
+            .sum();
+        assert_eq!(sum_pairwise_manhattan(&positions), brute_force_sum);
+        assert_eq!(
+            centroid(&positions),
+            Some([1061.0 / 5.0, -5964.0 / 5.0, 2227.0 / 5.0])
+        );
+    }
+
+    #[test]
+    fn coordinate_test() {
+        let points = [
+            Coordinate { x: 1, y: 6 },
+            Coordinate { x: 5, y: 11 },
+            Coordinate { x: 4, y: 0 },
+        ];
+        assert_eq!(max_pairwise_manhattan(&points), 12);
+        assert_eq!(sum_pairwise_manhattan(&points), 9 + 9 + 12);
+        assert_eq!(centroid(&points), Some([10.0 / 3.0, 17.0 / 3.0]));
+        assert_eq!(centroid::<Coordinate, 2>(&[]), None);
+        assert_eq!(max_pairwise_manhattan::<Coordinate, 2>(&[]), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "doesn't fit in an isize")]
+    fn coordinate_overflow_test() {
+        Coordinate {
+            x: usize::MAX,
+            y: 0,
+        }
+        .components();
+    }
+}
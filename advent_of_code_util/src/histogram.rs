@@ -0,0 +1,66 @@
+use std::{collections::HashMap, fmt::Display};
+
+use itertools::Itertools;
+
+/**
+one line per key in sorted order, with bars scaled so the biggest count is max_width wide. any non zero count gets at least one '#'
+*/
+pub fn histogram_lines<K: Ord + Display>(
+    counter: &HashMap<K, usize>,
+    max_width: usize,
+) -> Vec<String> {
+    let largest_count = counter.values().copied().max().unwrap_or(0);
+    let key_width = counter
+        .keys()
+        .map(|key| key.to_string().len())
+        .max()
+        .unwrap_or(0);
+    counter
+        .iter()
+        .sorted_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(key, &count)| {
+            let bar_length = if count == 0 {
+                0
+            } else {
+                (count as u128 * max_width as u128).div_ceil(largest_count as u128) as usize
+            };
+            format!(
+                "{:>key_width$} | {:<max_width$} {}",
+                key.to_string(),
+                "#".repeat(bar_length),
+                count
+            )
+        })
+        .collect()
+}
+
+pub fn print_histogram<K: Ord + Display>(counter: &HashMap<K, usize>, max_width: usize) {
+    for line in histogram_lines(counter, max_width) {
+        println!("{}", line);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use crate::histogram::*;
+
+    #[test]
+    fn histogram_lines_test() {
+        let timers = [3, 4, 3, 1, 2].into_iter().counts();
+        assert_eq!(
+            histogram_lines(&timers, 4),
+            vec!["1 | ##   1", "2 | ##   1", "3 | #### 2", "4 | ##   1"]
+        );
+        let elements = HashMap::from([('N', 0), ('B', 1749), ('H', 161)]);
+        assert_eq!(
+            histogram_lines(&elements, 10),
+            vec![
+                "B | ########## 1749",
+                "H | #          161",
+                "N |            0"
+            ]
+        );
+    }
+}
@@ -42,3 +42,44 @@ impl Sub for ICoordinate {
         }
     }
 }
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, PartialOrd, Ord)]
+pub struct ICoordinate3 {
+    pub x: isize,
+    pub y: isize,
+    pub z: isize,
+}
+impl FromStr for ICoordinate3 {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y, z) = s
+            .split(',')
+            .map(|num| num.parse::<isize>().unwrap())
+            .collect_tuple::<(isize, isize, isize)>()
+            .unwrap();
+        Ok(ICoordinate3 { x, y, z })
+    }
+}
+impl Add for ICoordinate3 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        ICoordinate3 {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+impl Sub for ICoordinate3 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        ICoordinate3 {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use std::{cell::OnceCell, fs, path::Path};
+
+use itertools::Itertools;
+
+use crate::parse::lines_to_blocks;
+
+/**
+holds the whole input file and parses each view of it the first time it's asked for
+*/
+pub struct Input {
+    raw: String,
+    lines: OnceCell<Vec<String>>,
+    blocks: OnceCell<Vec<Vec<String>>>,
+    grid: OnceCell<Vec<Vec<char>>>,
+    numbers: OnceCell<Vec<i64>>,
+}
+impl Input {
+    pub fn new(raw: String) -> Self {
+        Input {
+            raw,
+            lines: OnceCell::new(),
+            blocks: OnceCell::new(),
+            grid: OnceCell::new(),
+            numbers: OnceCell::new(),
+        }
+    }
+
+    pub fn from_file<P>(filename: P) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        Input::new(fs::read_to_string(filename).unwrap())
+    }
+
+    pub fn raw(&self) -> &str {
+        &self.raw
+    }
+
+    pub fn lines(&self) -> &[String] {
+        self.lines
+            .get_or_init(|| self.raw.lines().map(|line| line.to_string()).collect())
+    }
+
+    pub fn blocks(&self) -> &[Vec<String>] {
+        self.blocks
+            .get_or_init(|| lines_to_blocks(self.lines().iter().cloned()))
+    }
+
+    pub fn grid(&self) -> &[Vec<char>] {
+        self.grid.get_or_init(|| {
+            self.lines()
+                .iter()
+                .map(|line| line.chars().collect_vec())
+                .collect()
+        })
+    }
+
+    /**
+    every integer in the input in order, a '-' only makes a number negative if it isn't right after another digit (so 2-4 is 2 and 4)
+    runs of digits that don't fit in an i64 are skipped, so long digit strings need to come from lines() or grid() instead
+    */
+    pub fn numbers(&self) -> &[i64] {
+        self.numbers.get_or_init(|| {
+            let chars = self.raw.chars().collect_vec();
+            let mut numbers = vec![];
+            let mut i = 0;
+            while i < chars.len() {
+                let negative = chars[i] == '-'
+                    && chars.get(i + 1).is_some_and(|c| c.is_ascii_digit())
+                    && (i == 0 || !chars[i - 1].is_ascii_digit());
+                let start = if negative { i + 1 } else { i };
+                let end = (start..chars.len())
+                    .find(|&j| !chars[j].is_ascii_digit())
+                    .unwrap_or(chars.len());
+                if end > start {
+                    if let Ok(number) = chars[i..end].iter().collect::<String>().parse::<i64>() {
+                        numbers.push(number);
+                    }
+                    i = end;
+                } else {
+                    i += 1;
+                }
+            }
+            numbers
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{fixtures, input::*};
+
+    #[test]
+    fn views_test() {
+        let input = Input::new(fixtures::DAY_4.to_string());
+        assert_eq!(input.lines().len(), 19);
+        assert_eq!(input.blocks().len(), 4);
+        assert_eq!(input.blocks()[1][0], "22 13 17 11  0");
+        assert_eq!(input.grid()[2][..2], ['2', '2']);
+        assert_eq!(input.numbers().len(), 27 + 3 * 25);
+    }
+
+    #[test]
+    fn numbers_test() {
+        let input = Input::new("target area: x=20..30, y=-10..-5\n2-4,6-8 -x".to_string());
+        assert_eq!(input.numbers(), [20, 30, -10, -5, 2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn numbers_overflow_test() {
+        let input = Input::new(format!(
+            "{}\n12 -9223372036854775808",
+            "1234567890".repeat(65)
+        ));
+        assert_eq!(input.numbers(), [12, i64::MIN]);
+        assert_eq!(input.lines()[0].len(), 650);
+    }
+}
@@ -0,0 +1,71 @@
+use std::ops::RangeInclusive;
+
+use itertools::Itertools;
+
+pub struct IntervalIndex<T> {
+    // sorted by start, each paired with its position in the input
+    intervals: Vec<(usize, RangeInclusive<T>)>,
+    // max_ends[i] is the largest end among intervals[0..=i], which lets queries stop early
+    max_ends: Vec<T>,
+}
+impl<T: Ord + Copy> IntervalIndex<T> {
+    pub fn build<I>(intervals: I) -> Self
+    where
+        I: IntoIterator<Item = RangeInclusive<T>>,
+    {
+        let intervals = intervals
+            .into_iter()
+            .enumerate()
+            .sorted_by_key(|(_, range)| *range.start())
+            .collect_vec();
+        let max_ends = intervals
+            .iter()
+            .scan(None, |max_end: &mut Option<T>, (_, range)| {
+                let new_max = max_end.map_or(*range.end(), |m| std::cmp::max(m, *range.end()));
+                *max_end = Some(new_max);
+                Some(new_max)
+            })
+            .collect_vec();
+        IntervalIndex {
+            intervals,
+            max_ends,
+        }
+    }
+
+    /**
+    yields every interval containing `point` along with its index in the input to `build`, in no particular order
+    */
+    pub fn query(&self, point: T) -> impl Iterator<Item = (usize, &RangeInclusive<T>)> + '_ {
+        let candidates = self
+            .intervals
+            .partition_point(|(_, range)| *range.start() <= point);
+        (0..candidates)
+            .rev()
+            .take_while(move |&i| self.max_ends[i] >= point)
+            .map(|i| (self.intervals[i].0, &self.intervals[i].1))
+            .filter(move |(_, range)| *range.end() >= point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use crate::interval::*;
+
+    #[test]
+    fn query_test() {
+        let index = IntervalIndex::build(vec![5..=10, 0..=20, 12..=15, 8..=8, -3..=-1]);
+        assert_eq!(
+            index.query(8).map(|(i, _)| i).sorted().collect_vec(),
+            vec![0, 1, 3]
+        );
+        assert_eq!(
+            index.query(12).map(|(i, _)| i).sorted().collect_vec(),
+            vec![1, 2]
+        );
+        assert_eq!(index.query(-2).collect_vec(), vec![(4, &(-3..=-1))]);
+        assert_eq!(index.query(21).count(), 0);
+        assert_eq!(index.query(-10).count(), 0);
+    }
+}
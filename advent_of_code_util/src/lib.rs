@@ -1,9 +1,19 @@
 use itertools::Itertools;
 use std::{collections::BTreeSet, str::FromStr};
 
+pub mod cast;
+pub mod fixed_grid;
+pub mod fixtures;
+pub mod geometry;
+pub mod histogram;
 pub mod icoordinate;
+pub mod input;
+pub mod interval;
 pub mod matrix;
+pub mod monotonic;
 pub mod parse;
+pub mod search;
+pub mod sequences;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
 pub enum Direction {
@@ -284,3 +294,37 @@ macro_rules! base_aoc_ignore_tests {
         }
     };
 }
+
+/**
+expects parse_input(input_file) plus part_1 and part_2 functions that both take a reference to whatever parse_input returns, so parsing only happens once and each stage is timed on its own
+*/
+#[macro_export]
+macro_rules! parsed_aoc {
+    ( $part_1_answer:literal, $part_2_answer:literal ) => {
+        #[cfg(test)]
+        mod tests {
+            use super::*;
+
+            #[test]
+            fn main() {
+                let file_path = format!("{}/testinput", env!("CARGO_MANIFEST_DIR"));
+                let parsed = parse_input(&file_path);
+                assert_eq!(part_1(&parsed), $part_1_answer);
+                assert_eq!(part_2(&parsed), $part_2_answer);
+            }
+        }
+
+        fn main() {
+            let file_path = format!("{}/input", env!("CARGO_MANIFEST_DIR"));
+            let start = std::time::Instant::now();
+            let parsed = parse_input(&file_path);
+            println!("Parsed input in {:?}", start.elapsed());
+            let start = std::time::Instant::now();
+            let part_1_output = part_1(&parsed);
+            println!("Part 1 output: {} ({:?})", part_1_output, start.elapsed());
+            let start = std::time::Instant::now();
+            let part_2_output = part_2(&parsed);
+            println!("Part 2 output: {} ({:?})", part_2_output, start.elapsed());
+        }
+    };
+}
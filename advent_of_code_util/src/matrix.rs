@@ -1,5 +1,7 @@
 use itertools::Itertools;
 
+use crate::Coordinate;
+
 pub fn transpose2<T>(v: Vec<Vec<T>>) -> Vec<Vec<T>> {
     assert!(!v.is_empty());
     let len = v[0].len();
@@ -17,3 +19,232 @@ pub fn transpose2<T>(v: Vec<Vec<T>>) -> Vec<Vec<T>> {
 pub fn clone_column<T: Clone>(v: &[Vec<T>], x: usize) -> Vec<T> {
     v.iter().map(|row| row[x].clone()).collect_vec()
 }
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
+pub enum Transform {
+    Identity,
+    RotateClockwise,
+    Rotate180,
+    RotateCounterClockwise,
+    FlipHorizontal,
+    FlipVertical,
+    Transpose,
+    AntiTranspose,
+}
+impl Transform {
+    pub const ALL: [Transform; 8] = [
+        Transform::Identity,
+        Transform::RotateClockwise,
+        Transform::Rotate180,
+        Transform::RotateCounterClockwise,
+        Transform::FlipHorizontal,
+        Transform::FlipVertical,
+        Transform::Transpose,
+        Transform::AntiTranspose,
+    ];
+}
+
+pub fn rotate_clockwise<T: Clone>(v: &[Vec<T>]) -> Vec<Vec<T>> {
+    let width = v.first().map_or(0, |row| row.len());
+    (0..width)
+        .map(|x| v.iter().rev().map(|row| row[x].clone()).collect_vec())
+        .collect()
+}
+
+pub fn rotate_counter_clockwise<T: Clone>(v: &[Vec<T>]) -> Vec<Vec<T>> {
+    let width = v.first().map_or(0, |row| row.len());
+    (0..width)
+        .rev()
+        .map(|x| v.iter().map(|row| row[x].clone()).collect_vec())
+        .collect()
+}
+
+pub fn flip_horizontal<T: Clone>(v: &[Vec<T>]) -> Vec<Vec<T>> {
+    v.iter()
+        .map(|row| row.iter().rev().cloned().collect_vec())
+        .collect()
+}
+
+pub fn flip_vertical<T: Clone>(v: &[Vec<T>]) -> Vec<Vec<T>> {
+    v.iter().rev().cloned().collect()
+}
+
+pub fn apply_transform<T: Clone>(v: &[Vec<T>], transform: Transform) -> Vec<Vec<T>> {
+    match transform {
+        Transform::Identity => v.to_vec(),
+        Transform::RotateClockwise => rotate_clockwise(v),
+        Transform::Rotate180 => flip_vertical(&flip_horizontal(v)),
+        Transform::RotateCounterClockwise => rotate_counter_clockwise(v),
+        Transform::FlipHorizontal => flip_horizontal(v),
+        Transform::FlipVertical => flip_vertical(v),
+        Transform::Transpose => flip_horizontal(&rotate_clockwise(v)),
+        Transform::AntiTranspose => flip_vertical(&rotate_clockwise(v)),
+    }
+}
+
+/**
+returns the first transform (in `Transform::ALL` order) that turns `a` into `b`, so identical grids always give `Transform::Identity`
+*/
+pub fn equivalent_under_symmetry<T: Clone + PartialEq>(
+    a: &[Vec<T>],
+    b: &[Vec<T>],
+) -> Option<Transform> {
+    Transform::ALL
+        .into_iter()
+        .find(|&transform| apply_transform(a, transform) == b)
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Adjacency {
+    NonDiagonal,
+    WithDiagonals,
+}
+
+/**
+turns a grid into a neighbor function for the search module, the cost of a move is the cost of the cell being entered and cells where cost_fn returns None can't be entered
+*/
+pub fn as_weighted_graph<'a, T, F>(
+    v: &'a [Vec<T>],
+    cost_fn: F,
+    adjacency: Adjacency,
+) -> impl Fn(&Coordinate) -> Vec<(Coordinate, usize)> + 'a
+where
+    F: Fn(&T) -> Option<usize> + 'a,
+{
+    let height = v.len();
+    let width = v.first().map_or(0, |row| row.len());
+    move |position| {
+        let surrounding = match adjacency {
+            Adjacency::NonDiagonal => {
+                position.get_surrounding_non_diagonal_coordinates(width, height)
+            }
+            Adjacency::WithDiagonals => position.get_surrounding_coordinates(width, height),
+        };
+        surrounding
+            .into_iter()
+            .filter_map(|neighbor| cost_fn(&v[neighbor.y][neighbor.x]).map(|cost| (neighbor, cost)))
+            .collect()
+    }
+}
+
+/**
+gives every cell a region label from 0 up to (but not including) the returned count, where non diagonal neighbors are in the same region whenever is_same_region says so
+*/
+pub fn label_regions<T, F>(v: &[Vec<T>], mut is_same_region: F) -> (Vec<Vec<u32>>, usize)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let height = v.len();
+    let width = v.first().map_or(0, |row| row.len());
+    let mut labels: Vec<Vec<Option<u32>>> = vec![vec![None; width]; height];
+    let mut num_regions = 0;
+    for (y, x) in (0..height).cartesian_product(0..width) {
+        if labels[y][x].is_some() {
+            continue;
+        }
+        let label = num_regions as u32;
+        num_regions += 1;
+        labels[y][x] = Some(label);
+        let mut to_visit = vec![Coordinate { x, y }];
+        while let Some(position) = to_visit.pop() {
+            for neighbor in position.get_surrounding_non_diagonal_coordinates(width, height) {
+                if labels[neighbor.y][neighbor.x].is_none()
+                    && is_same_region(&v[position.y][position.x], &v[neighbor.y][neighbor.x])
+                {
+                    labels[neighbor.y][neighbor.x] = Some(label);
+                    to_visit.push(neighbor);
+                }
+            }
+        }
+    }
+    let labels = labels
+        .into_iter()
+        .map(|row| row.into_iter().map(|label| label.unwrap()).collect_vec())
+        .collect();
+    (labels, num_regions)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{fixtures, matrix::*};
+
+    #[test]
+    fn transforms_test() {
+        let grid = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        assert_eq!(
+            apply_transform(&grid, Transform::RotateClockwise),
+            vec![vec![4, 1], vec![5, 2], vec![6, 3]]
+        );
+        assert_eq!(
+            apply_transform(&grid, Transform::RotateCounterClockwise),
+            vec![vec![3, 6], vec![2, 5], vec![1, 4]]
+        );
+        assert_eq!(
+            apply_transform(&grid, Transform::Rotate180),
+            vec![vec![6, 5, 4], vec![3, 2, 1]]
+        );
+        assert_eq!(
+            apply_transform(&grid, Transform::Transpose),
+            vec![vec![1, 4], vec![2, 5], vec![3, 6]]
+        );
+        assert_eq!(
+            apply_transform(&grid, Transform::AntiTranspose),
+            vec![vec![6, 3], vec![5, 2], vec![4, 1]]
+        );
+    }
+
+    #[test]
+    fn equivalent_under_symmetry_test() {
+        let grid = vec![vec!['#', '.', '.'], vec!['#', '#', '.']];
+        for transform in Transform::ALL {
+            let transformed = apply_transform(&grid, transform);
+            let found = equivalent_under_symmetry(&grid, &transformed).unwrap();
+            assert_eq!(apply_transform(&grid, found), transformed);
+        }
+        assert_eq!(
+            equivalent_under_symmetry(&grid, &grid),
+            Some(Transform::Identity)
+        );
+        assert_eq!(
+            equivalent_under_symmetry(&grid, &[vec!['#', '#', '#'], vec!['.', '.', '.']]),
+            None
+        );
+    }
+
+    #[test]
+    fn label_regions_test() {
+        let grid = vec![
+            vec!['A', 'A', 'B', 'B'],
+            vec!['A', 'B', 'B', 'A'],
+            vec!['C', 'C', 'A', 'A'],
+        ];
+        let (labels, num_regions) = label_regions(&grid, |a, b| a == b);
+        assert_eq!(num_regions, 4);
+        assert_eq!(
+            labels,
+            vec![vec![0, 0, 1, 1], vec![0, 1, 1, 2], vec![3, 3, 2, 2]]
+        );
+        assert_eq!(label_regions(&grid, |_, _| true).1, 1);
+    }
+
+    #[test]
+    fn label_regions_2021_day_9_test() {
+        let heightmap = fixtures::DAY_9
+            .lines()
+            .map(|line| line.chars().collect_vec())
+            .collect_vec();
+        let (labels, _) = label_regions(&heightmap, |a, b| (*a == '9') == (*b == '9'));
+        let basin_size_product: usize = labels
+            .iter()
+            .flatten()
+            .zip(heightmap.iter().flatten())
+            .filter(|(_, &height)| height != '9')
+            .counts_by(|(&label, _)| label)
+            .into_values()
+            .sorted()
+            .rev()
+            .take(3)
+            .product();
+        assert_eq!(basin_size_product, 1134);
+    }
+}
@@ -0,0 +1,60 @@
+/**
+for each element, the index of the nearest element to its right that is strictly greater, or None if there isn't one
+*/
+pub fn next_greater_indices<T: Ord>(values: &[T]) -> Vec<Option<usize>> {
+    let mut result = vec![None; values.len()];
+    let mut stack: Vec<usize> = vec![];
+    for (i, value) in values.iter().enumerate() {
+        while let Some(&top) = stack.last() {
+            if values[top] < *value {
+                result[top] = Some(i);
+                stack.pop();
+            } else {
+                break;
+            }
+        }
+        stack.push(i);
+    }
+    result
+}
+
+pub fn largest_rectangle_in_histogram(heights: &[u64]) -> u64 {
+    let mut largest = 0;
+    // indices of bars with increasing heights, each bar extends left to just after the one below it
+    let mut stack: Vec<usize> = vec![];
+    for i in 0..=heights.len() {
+        let height = heights.get(i).copied().unwrap_or(0);
+        while let Some(&top) = stack.last() {
+            if heights[top] < height {
+                break;
+            }
+            stack.pop();
+            let left = stack.last().map_or(0, |&below| below + 1);
+            largest = std::cmp::max(largest, heights[top] * (i - left) as u64);
+        }
+        stack.push(i);
+    }
+    largest
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::monotonic::*;
+
+    #[test]
+    fn next_greater_indices_test() {
+        assert_eq!(
+            next_greater_indices(&[3, 0, 3, 7, 3]),
+            vec![Some(3), Some(2), Some(3), None, None]
+        );
+        assert_eq!(next_greater_indices::<u8>(&[]), vec![]);
+    }
+
+    #[test]
+    fn largest_rectangle_in_histogram_test() {
+        assert_eq!(largest_rectangle_in_histogram(&[2, 1, 5, 6, 2, 3]), 10);
+        assert_eq!(largest_rectangle_in_histogram(&[2, 4]), 4);
+        assert_eq!(largest_rectangle_in_histogram(&[3, 3, 3]), 9);
+        assert_eq!(largest_rectangle_in_histogram(&[]), 0);
+    }
+}
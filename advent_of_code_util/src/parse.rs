@@ -78,9 +78,16 @@ where
     P: AsRef<Path>,
 {
     let file = File::open(filename).unwrap();
+    lines_to_blocks(io::BufReader::new(file).lines().map(|line| line.unwrap()))
+}
+
+pub fn lines_to_blocks<I>(lines: I) -> Vec<Vec<String>>
+where
+    I: IntoIterator<Item = String>,
+{
     let mut blocks = vec![];
     let mut latest_block = vec![];
-    for line in io::BufReader::new(file).lines().map(|line| line.unwrap()) {
+    for line in lines {
         if line.is_empty() {
             blocks.push(latest_block);
             latest_block = vec![];
@@ -0,0 +1,741 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+use itertools::Itertools;
+
+/**
+returns the first goal popped off the queue along with its cost, neighbors yields (node, cost to move there) pairs
+*/
+pub fn dijkstra<N, FN, IN, FG>(start: N, neighbors: FN, is_goal: FG) -> Option<(N, usize)>
+where
+    N: Clone + Eq + Hash + Ord,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, usize)>,
+    FG: FnMut(&N) -> bool,
+{
+    a_star(start, neighbors, |_| 0, is_goal)
+}
+
+/**
+same as dijkstra, but the heuristic must never overestimate the remaining cost or the answer may not be optimal
+*/
+pub fn a_star<N, FN, IN, FH, FG>(
+    start: N,
+    neighbors: FN,
+    heuristic: FH,
+    mut is_goal: FG,
+) -> Option<(N, usize)>
+where
+    N: Clone + Eq + Hash + Ord,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, usize)>,
+    FH: FnMut(&N) -> usize,
+    FG: FnMut(&N) -> bool,
+{
+    let mut found = None;
+    settle_nodes(start, neighbors, heuristic, |node, cost| {
+        if is_goal(node) {
+            found = Some((node.clone(), cost));
+            true
+        } else {
+            false
+        }
+    });
+    found
+}
+
+pub fn dijkstra_path<N, FN, IN, FG>(start: N, neighbors: FN, is_goal: FG) -> Option<(Vec<N>, usize)>
+where
+    N: Clone + Eq + Hash + Ord,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, usize)>,
+    FG: FnMut(&N) -> bool,
+{
+    a_star_path(start, neighbors, |_| 0, is_goal)
+}
+
+pub fn a_star_path<N, FN, IN, FH, FG>(
+    start: N,
+    neighbors: FN,
+    heuristic: FH,
+    mut is_goal: FG,
+) -> Option<(Vec<N>, usize)>
+where
+    N: Clone + Eq + Hash + Ord,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, usize)>,
+    FH: FnMut(&N) -> usize,
+    FG: FnMut(&N) -> bool,
+{
+    let mut found = None;
+    let predecessors = settle_nodes(start, neighbors, heuristic, |node, cost| {
+        if is_goal(node) {
+            found = Some((node.clone(), cost));
+            true
+        } else {
+            false
+        }
+    });
+    found.map(|(goal, cost)| (reconstruct_path(&predecessors, goal), cost))
+}
+
+/**
+explores everything reachable from start and returns the cost of every goal found on the way
+*/
+pub fn dijkstra_all_goals<N, FN, IN, FG>(
+    start: N,
+    neighbors: FN,
+    mut is_goal: FG,
+) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash + Ord,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, usize)>,
+    FG: FnMut(&N) -> bool,
+{
+    let mut goals = HashMap::new();
+    settle_nodes(
+        start,
+        neighbors,
+        |_| 0,
+        |node, cost| {
+            if is_goal(node) {
+                goals.insert(node.clone(), cost);
+            }
+            false
+        },
+    );
+    goals
+}
+
+/**
+returns the minimal cost to reach any goal along with every goal reachable at exactly that cost
+*/
+pub fn dijkstra_nearest_goals<N, FN, IN, FG>(
+    start: N,
+    neighbors: FN,
+    mut is_goal: FG,
+) -> Option<(usize, Vec<N>)>
+where
+    N: Clone + Eq + Hash + Ord,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, usize)>,
+    FG: FnMut(&N) -> bool,
+{
+    let mut nearest: Option<(usize, Vec<N>)> = None;
+    settle_nodes(
+        start,
+        neighbors,
+        |_| 0,
+        |node, cost| {
+            match &mut nearest {
+                Some((best_cost, _)) if cost > *best_cost => return true,
+                Some((_, goals)) => {
+                    if is_goal(node) {
+                        goals.push(node.clone());
+                    }
+                }
+                None => {
+                    if is_goal(node) {
+                        nearest = Some((cost, vec![node.clone()]));
+                    }
+                }
+            }
+            false
+        },
+    );
+    nearest
+}
+
+/**
+returns the first goal found along with the number of steps to reach it
+*/
+pub fn bfs<N, FN, IN, FG>(start: N, mut neighbors: FN, mut is_goal: FG) -> Option<(N, usize)>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((node, steps)) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some((node, steps));
+        }
+        for next in neighbors(&node) {
+            if seen.insert(next.clone()) {
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+    None
+}
+
+pub fn bfs_path<N, FN, IN, FG>(start: N, mut neighbors: FN, mut is_goal: FG) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    let mut predecessors: HashMap<N, N> = HashMap::new();
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(reconstruct_path(&predecessors, node));
+        }
+        for next in neighbors(&node) {
+            if seen.insert(next.clone()) {
+                predecessors.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/**
+the number of nodes first reached at each depth, up to and including max_depth (or until nothing new can be reached).
+on grids where every step flips the parity of x + y, the nodes reachable in exactly n steps are the ones at depths n, n - 2, n - 4, ...
+*/
+pub fn bfs_frontier_sizes<N, FN, IN>(start: N, mut neighbors: FN, max_depth: usize) -> Vec<usize>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::from([start.clone()]);
+    let mut frontier = vec![start];
+    let mut sizes = vec![];
+    while !frontier.is_empty() {
+        sizes.push(frontier.len());
+        if sizes.len() > max_depth {
+            break;
+        }
+        frontier = frontier
+            .iter()
+            .flat_map(&mut neighbors)
+            .filter(|next| seen.insert(next.clone()))
+            .collect();
+    }
+    sizes
+}
+
+/**
+returns the number of steps from start to goal by searching from both ends at once, always growing whichever frontier is smaller.
+neighbors_back must yield the nodes that can step to the given node, which is the same as neighbors_fwd for undirected graphs
+*/
+pub fn bidirectional_bfs<N, FF, IF, FB, IB>(
+    start: N,
+    goal: N,
+    mut neighbors_fwd: FF,
+    mut neighbors_back: FB,
+) -> Option<usize>
+where
+    N: Clone + Eq + Hash,
+    FF: FnMut(&N) -> IF,
+    IF: IntoIterator<Item = N>,
+    FB: FnMut(&N) -> IB,
+    IB: IntoIterator<Item = N>,
+{
+    if start == goal {
+        return Some(0);
+    }
+    let mut fwd_steps = HashMap::from([(start.clone(), 0)]);
+    let mut back_steps = HashMap::from([(goal.clone(), 0)]);
+    let mut fwd_frontier = vec![start];
+    let mut back_frontier = vec![goal];
+
+    while !fwd_frontier.is_empty() && !back_frontier.is_empty() {
+        let best = if fwd_frontier.len() <= back_frontier.len() {
+            expand_layer(
+                &mut fwd_frontier,
+                &mut fwd_steps,
+                &back_steps,
+                &mut neighbors_fwd,
+            )
+        } else {
+            expand_layer(
+                &mut back_frontier,
+                &mut back_steps,
+                &fwd_steps,
+                &mut neighbors_back,
+            )
+        };
+        if best.is_some() {
+            return best;
+        }
+    }
+    None
+}
+
+/**
+replaces the frontier with the next full layer, returning the shortest total path through any node the other side has already seen
+*/
+fn expand_layer<N, FN, IN>(
+    frontier: &mut Vec<N>,
+    steps: &mut HashMap<N, usize>,
+    other_steps: &HashMap<N, usize>,
+    neighbors: &mut FN,
+) -> Option<usize>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut best: Option<usize> = None;
+    let mut next_frontier = vec![];
+    for node in frontier.iter() {
+        let next_steps = steps[node] + 1;
+        for next in neighbors(node) {
+            if steps.contains_key(&next) {
+                continue;
+            }
+            if let Some(other) = other_steps.get(&next) {
+                best = Some(best.map_or(next_steps + other, |b| b.min(next_steps + other)));
+            }
+            steps.insert(next.clone(), next_steps);
+            next_frontier.push(next);
+        }
+    }
+    *frontier = next_frontier;
+    best
+}
+
+/**
+keeps only the beam_width best scoring states (highest score first, ties broken by the smaller state) at every depth and returns the best state ever seen.
+states are never revisited, and the search stops once a generation has nothing new to expand into
+*/
+pub fn beam_search<N, FE, IE, FS, S>(
+    start: N,
+    mut expand: FE,
+    mut score: FS,
+    beam_width: usize,
+) -> (N, S)
+where
+    N: Clone + Eq + Hash + Ord,
+    FE: FnMut(&N) -> IE,
+    IE: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> S,
+    S: Ord + Clone,
+{
+    let mut seen = HashSet::from([start.clone()]);
+    let mut best = (start.clone(), score(&start));
+    let mut beam = vec![start];
+
+    while !beam.is_empty() {
+        let mut candidates = beam
+            .iter()
+            .flat_map(&mut expand)
+            .filter(|next| seen.insert(next.clone()))
+            .map(|next| (score(&next), next))
+            .collect_vec();
+        candidates
+            .sort_by(|(a_score, a), (b_score, b)| b_score.cmp(a_score).then_with(|| a.cmp(b)));
+        candidates.truncate(beam_width);
+        if let Some((top_score, top)) = candidates.first() {
+            if *top_score > best.1 {
+                best = (top.clone(), top_score.clone());
+            }
+        }
+        beam = candidates.into_iter().map(|(_, next)| next).collect();
+    }
+    best
+}
+
+/**
+depth limited DFS run with limits 0 through max_depth, so the first path found from start to a goal has as few steps as possible.
+only the current path is kept in memory, which means nodes can be explored many times on graphs with lots of ways to reach them
+*/
+pub fn iddfs<N, FN, IN, FG>(
+    start: N,
+    mut neighbors: FN,
+    mut is_goal: FG,
+    max_depth: usize,
+) -> Option<Vec<N>>
+where
+    N: Clone + Eq,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    (0..=max_depth).find_map(|depth_limit| {
+        let mut path = vec![start.clone()];
+        depth_limited_dfs(&mut path, depth_limit, &mut neighbors, &mut is_goal).then_some(path)
+    })
+}
+
+fn depth_limited_dfs<N, FN, IN, FG>(
+    path: &mut Vec<N>,
+    depth_left: usize,
+    neighbors: &mut FN,
+    is_goal: &mut FG,
+) -> bool
+where
+    N: Clone + Eq,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    let node = path.last().unwrap();
+    if is_goal(node) {
+        return true;
+    }
+    if depth_left == 0 {
+        return false;
+    }
+    for next in neighbors(node).into_iter().collect_vec() {
+        if path.contains(&next) {
+            continue;
+        }
+        path.push(next);
+        if depth_limited_dfs(path, depth_left - 1, neighbors, is_goal) {
+            return true;
+        }
+        path.pop();
+    }
+    false
+}
+
+/**
+follows predecessors back from end until reaching a node without one, returning the path in order from that node to end.
+panics if the predecessors loop back on themselves instead of reaching a start
+*/
+pub fn reconstruct_path<N>(predecessors: &HashMap<N, N>, end: N) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+{
+    let mut path = vec![end];
+    while let Some(previous) = predecessors.get(path.last().unwrap()) {
+        if path.len() > predecessors.len() {
+            panic!("Predecessors contain a cycle");
+        }
+        path.push(previous.clone());
+    }
+    path.reverse();
+    path
+}
+
+/**
+pops nodes in order of cost plus heuristic, calling on_settled once per node with its final cost until it returns true.
+returns the predecessor of every node it found a path to, which is final for every settled node
+*/
+fn settle_nodes<N, FN, IN, FH, FS>(
+    start: N,
+    mut neighbors: FN,
+    mut heuristic: FH,
+    mut on_settled: FS,
+) -> HashMap<N, N>
+where
+    N: Clone + Eq + Hash + Ord,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, usize)>,
+    FH: FnMut(&N) -> usize,
+    FS: FnMut(&N, usize) -> bool,
+{
+    let mut best_costs: HashMap<N, usize> = HashMap::from([(start.clone(), 0)]);
+    let mut predecessors: HashMap<N, N> = HashMap::new();
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), 0, start))]);
+
+    while let Some(Reverse((_, cost, node))) = heap.pop() {
+        if best_costs.get(&node).is_some_and(|&best| cost > best) {
+            continue;
+        }
+        if on_settled(&node, cost) {
+            break;
+        }
+        for (next, step_cost) in neighbors(&node) {
+            let next_cost = cost + step_cost;
+            if next_cost < *best_costs.get(&next).unwrap_or(&usize::MAX) {
+                best_costs.insert(next.clone(), next_cost);
+                predecessors.insert(next.clone(), node.clone());
+                heap.push(Reverse((next_cost + heuristic(&next), next_cost, next)));
+            }
+        }
+    }
+    predecessors
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fixtures,
+        matrix::{as_weighted_graph, Adjacency},
+        search::*,
+        Coordinate,
+    };
+
+    fn test_grid() -> Vec<Vec<char>> {
+        ["1163751", "13#1381", "21365#1", "3694931", "7463417"]
+            .iter()
+            .map(|line| line.chars().collect())
+            .collect()
+    }
+
+    #[test]
+    fn dijkstra_test() {
+        let grid = test_grid();
+        let goal = Coordinate { x: 6, y: 4 };
+        let neighbors = as_weighted_graph(
+            &grid,
+            |c| c.to_digit(10).map(|d| d as usize),
+            Adjacency::NonDiagonal,
+        );
+        assert_eq!(
+            dijkstra(Coordinate { x: 0, y: 0 }, &neighbors, |&c| c == goal),
+            Some((goal, 32))
+        );
+        assert_eq!(
+            a_star(
+                Coordinate { x: 0, y: 0 },
+                &neighbors,
+                |c| c.non_diagonal_distance(&goal),
+                |&c| c == goal
+            ),
+            Some((goal, 32))
+        );
+        assert_eq!(
+            dijkstra(Coordinate { x: 0, y: 0 }, &neighbors, |_| false),
+            None
+        );
+    }
+
+    #[test]
+    fn multiple_goals_test() {
+        // a line of nodes 0..10 where each step costs 1, plus a shortcut from 0 to 7
+        let neighbors = |&n: &usize| {
+            let mut next = vec![];
+            if n < 9 {
+                next.push((n + 1, 1));
+            }
+            if n == 0 {
+                next.push((7, 2));
+            }
+            next
+        };
+        assert_eq!(
+            dijkstra_all_goals(0, neighbors, |n| n % 3 == 0),
+            HashMap::from([(0, 0), (3, 3), (6, 6), (9, 4)])
+        );
+        assert_eq!(
+            dijkstra_nearest_goals(0, neighbors, |&n| n == 3 || n == 8),
+            Some((3, vec![3, 8]))
+        );
+        assert_eq!(dijkstra_nearest_goals(0, neighbors, |&n| n > 10), None);
+    }
+
+    #[test]
+    fn bidirectional_bfs_test() {
+        // permutations of 0..6 where you can swap the first two items or rotate the whole thing left
+        let fwd = |state: &Vec<u8>| {
+            let mut swapped = state.clone();
+            swapped.swap(0, 1);
+            let mut rotated = state.clone();
+            rotated.rotate_left(1);
+            vec![swapped, rotated]
+        };
+        let back = |state: &Vec<u8>| {
+            let mut swapped = state.clone();
+            swapped.swap(0, 1);
+            let mut rotated = state.clone();
+            rotated.rotate_right(1);
+            vec![swapped, rotated]
+        };
+        let start = vec![0, 1, 2, 3, 4, 5];
+        for goal in [
+            vec![0, 1, 2, 3, 4, 5],
+            vec![1, 0, 2, 3, 4, 5],
+            vec![5, 4, 3, 2, 1, 0],
+            vec![2, 0, 4, 1, 5, 3],
+            vec![1, 2, 3, 4, 5, 0],
+        ] {
+            let expected = bfs(start.clone(), fwd, |state| *state == goal).map(|(_, steps)| steps);
+            assert!(expected.is_some());
+            assert_eq!(bidirectional_bfs(start.clone(), goal, fwd, back), expected);
+        }
+
+        // 0 -> 1 -> 2 only goes one way
+        let line_fwd = |&n: &usize| if n < 2 { vec![n + 1] } else { vec![] };
+        let line_back = |&n: &usize| if n > 0 { vec![n - 1] } else { vec![] };
+        assert_eq!(bfs(0, line_fwd, |&n| n == 2), Some((2, 2)));
+        assert_eq!(bidirectional_bfs(0, 2, line_fwd, line_back), Some(2));
+        assert_eq!(bidirectional_bfs(2, 0, line_fwd, line_back), None);
+    }
+
+    #[test]
+    fn beam_search_test() {
+        // grow a string of digits, the score is the number it represents
+        let expand = |state: &String| {
+            if state.len() == 4 {
+                vec![]
+            } else {
+                (0..10).map(|d| format!("{}{}", state, d)).collect()
+            }
+        };
+        let score = |state: &String| state.parse::<usize>().unwrap_or(0);
+        assert_eq!(
+            beam_search(String::new(), expand, score, 1),
+            ("9999".to_string(), 9999)
+        );
+
+        // 13 ties with 6 but only a strictly better score replaces the best so far
+        let (best, best_score) = beam_search(
+            0usize,
+            |&n| {
+                if n < 20 {
+                    vec![n * 2 + 1, n * 2 + 2]
+                } else {
+                    vec![]
+                }
+            },
+            |&n| n % 7,
+            2,
+        );
+        assert_eq!((best, best_score), (6, 6));
+    }
+
+    #[test]
+    fn iddfs_test() {
+        let grid = test_grid();
+        let goal = Coordinate { x: 6, y: 4 };
+        let neighbors =
+            as_weighted_graph(&grid, |&c| (c != '#').then_some(1), Adjacency::NonDiagonal);
+        let unweighted = |c: &Coordinate| neighbors(c).into_iter().map(|(next, _)| next);
+        let path = iddfs(Coordinate { x: 0, y: 0 }, unweighted, |&c| c == goal, 20).unwrap();
+        assert_eq!(path.first(), Some(&Coordinate { x: 0, y: 0 }));
+        assert_eq!(path.last(), Some(&goal));
+        assert_eq!(
+            Some(path.len() - 1),
+            bfs(Coordinate { x: 0, y: 0 }, unweighted, |&c| c == goal).map(|(_, steps)| steps)
+        );
+        assert!(path
+            .iter()
+            .tuple_windows()
+            .all(|(a, b)| a.non_diagonal_distance(b) == 1));
+        assert_eq!(
+            iddfs(Coordinate { x: 0, y: 0 }, unweighted, |&c| c == goal, 9),
+            None
+        );
+    }
+
+    #[test]
+    fn bfs_frontier_sizes_test() {
+        let garden = [
+            "...........",
+            ".....###.#.",
+            ".###.##..#.",
+            "..#.#...#..",
+            "....#.#....",
+            ".##..S####.",
+            ".##..#...#.",
+            ".......##..",
+            ".##.#.####.",
+            ".##..##.##.",
+            "...........",
+        ]
+        .iter()
+        .map(|line| line.chars().collect_vec())
+        .collect_vec();
+        let neighbors = as_weighted_graph(
+            &garden,
+            |&c| (c != '#').then_some(1),
+            Adjacency::NonDiagonal,
+        );
+        let sizes = bfs_frontier_sizes(
+            Coordinate { x: 5, y: 5 },
+            |c| neighbors(c).into_iter().map(|(next, _)| next),
+            6,
+        );
+        assert_eq!(sizes.len(), 7);
+        assert_eq!(sizes[0] + sizes[2], 4);
+        assert_eq!(sizes.iter().step_by(2).sum::<usize>(), 16);
+
+        assert_eq!(
+            bfs_frontier_sizes(0, |&n: &usize| if n < 3 { vec![n + 1] } else { vec![] }, 10),
+            vec![1, 1, 1, 1]
+        );
+    }
+
+    #[test]
+    fn dijkstra_2021_day_15_test() {
+        let grid = fixtures::DAY_15
+            .lines()
+            .map(|line| line.chars().collect_vec())
+            .collect_vec();
+        let goal = Coordinate {
+            x: grid[0].len() - 1,
+            y: grid.len() - 1,
+        };
+        let neighbors = as_weighted_graph(
+            &grid,
+            |c| c.to_digit(10).map(|d| d as usize),
+            Adjacency::NonDiagonal,
+        );
+        assert_eq!(
+            dijkstra(Coordinate { x: 0, y: 0 }, neighbors, |&c| c == goal),
+            Some((goal, 40))
+        );
+    }
+
+    #[test]
+    fn path_test() {
+        let grid = test_grid();
+        let start = Coordinate { x: 0, y: 0 };
+        let goal = Coordinate { x: 6, y: 4 };
+        let neighbors = as_weighted_graph(
+            &grid,
+            |c| c.to_digit(10).map(|d| d as usize),
+            Adjacency::NonDiagonal,
+        );
+        let (path, cost) = dijkstra_path(start, &neighbors, |&c| c == goal).unwrap();
+        assert_eq!((path[0], path[path.len() - 1]), (start, goal));
+        assert_eq!(
+            path[1..]
+                .iter()
+                .map(|c| grid[c.y][c.x].to_digit(10).unwrap() as usize)
+                .sum::<usize>(),
+            cost
+        );
+        assert_eq!(
+            a_star_path(
+                start,
+                &neighbors,
+                |c| c.non_diagonal_distance(&goal),
+                |&c| c == goal
+            )
+            .map(|(_, cost)| cost),
+            Some(cost)
+        );
+
+        let unweighted = |c: &Coordinate| neighbors(c).into_iter().map(|(next, _)| next);
+        let path = bfs_path(start, unweighted, |&c| c == goal).unwrap();
+        assert_eq!(path.len() - 1, 10);
+        assert!(path
+            .iter()
+            .tuple_windows()
+            .all(|(a, b)| a.non_diagonal_distance(b) == 1));
+        assert_eq!(
+            bfs_path(start, unweighted, |&c| c == start),
+            Some(vec![start])
+        );
+    }
+
+    #[test]
+    fn reconstruct_path_test() {
+        let predecessors = HashMap::from([(3, 2), (2, 1), (1, 0), (5, 4)]);
+        assert_eq!(reconstruct_path(&predecessors, 3), vec![0, 1, 2, 3]);
+        assert_eq!(reconstruct_path(&predecessors, 0), vec![0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Predecessors contain a cycle")]
+    fn reconstruct_path_cycle_test() {
+        let predecessors = HashMap::from([(3, 2), (2, 1), (1, 3)]);
+        reconstruct_path(&predecessors, 3);
+    }
+}
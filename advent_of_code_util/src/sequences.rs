@@ -0,0 +1,73 @@
+/**
+the fewest single item insertions, deletions or substitutions needed to turn a into b
+*/
+pub fn levenshtein<T: PartialEq>(a: &[T], b: &[T]) -> usize {
+    let mut previous_row = (0..=b.len()).collect::<Vec<usize>>();
+    for (i, a_item) in a.iter().enumerate() {
+        let mut row = vec![i + 1; b.len() + 1];
+        for (j, b_item) in b.iter().enumerate() {
+            let substitution_cost = if a_item == b_item { 0 } else { 1 };
+            row[j + 1] = (previous_row[j] + substitution_cost)
+                .min(previous_row[j + 1] + 1)
+                .min(row[j] + 1);
+        }
+        previous_row = row;
+    }
+    previous_row[b.len()]
+}
+
+pub fn longest_common_subsequence<T: PartialEq + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    // lengths[i][j] is the length of the longest common subsequence of a[i..] and b[j..]
+    let mut lengths = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lengths[i][j] = if a[i] == b[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut subsequence = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            subsequence.push(a[i].clone());
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    subsequence
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use crate::sequences::*;
+
+    #[test]
+    fn levenshtein_test() {
+        assert_eq!(levenshtein(b"kitten", b"sitting"), 3);
+        assert_eq!(levenshtein(b"", b"abc"), 3);
+        assert_eq!(levenshtein(&[1, 2, 3], &[1, 2, 3]), 0);
+    }
+
+    #[test]
+    fn longest_common_subsequence_test() {
+        let a = "ABCBDAB".chars().collect_vec();
+        let b = "BDCABA".chars().collect_vec();
+        let subsequence = longest_common_subsequence(&a, &b);
+        assert_eq!(subsequence.len(), 4);
+        for sequence in [&a, &b] {
+            let mut items = sequence.iter();
+            assert!(subsequence.iter().all(|c| items.any(|other| other == c)));
+        }
+        assert_eq!(longest_common_subsequence(b"abc", b"xyz"), vec![]);
+    }
+}
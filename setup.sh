@@ -8,15 +8,21 @@ fi
 cargo init $1
 
 cat >./$1/src/main.rs << END
-use advent_of_code_util::{base_aoc, parse::read_lines};
+use advent_of_code_util::{parse::read_lines, parsed_aoc};
 
-fn get_program_output(input_file: &str) -> (usize, usize) {
-    let input = read_lines(input_file);
+fn parse_input(input_file: &str) -> Vec<String> {
+    read_lines(input_file)
+}
+
+fn part_1(input: &[String]) -> usize {
+    0
+}
 
-    (0, 0)
+fn part_2(input: &[String]) -> usize {
+    0
 }
 
-base_aoc!(0, 0);
+parsed_aoc!(0, 0);
 END
 
 cat >./$1/Cargo.toml << END